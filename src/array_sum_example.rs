@@ -2,22 +2,109 @@ use std::marker::PhantomData;
 
 use halo2_proofs::{
     circuit::*,
-    plonk::*, poly::Rotation, pasta::Fp, arithmetic::Field, dev::MockProver,
+    plonk::*, poly::Rotation, pasta::{Fp, EqAffine}, arithmetic::Field,
+    poly::commitment::Params,
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255, TranscriptRead},
 };
+use rand_core::{OsRng, RngCore};
+#[cfg(test)]
+use halo2_proofs::dev::MockProver;
+
+// The binary gate folded over the array. `Max`/other reductions can be added here
+// as long as they come with an identity element and a gate polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReductionOp {
+    Sum,
+    Product,
+}
+
+impl ReductionOp {
+    fn identity<F: Field>(&self) -> F {
+        match self {
+            ReductionOp::Sum => F::ZERO,
+            ReductionOp::Product => F::ONE,
+        }
+    }
+
+    fn fold<F: Field>(&self, acc: F, x: F) -> F {
+        match self {
+            ReductionOp::Sum => acc + x,
+            ReductionOp::Product => acc * x,
+        }
+    }
+}
+
+// Picks a `ReductionOp` at the type level, so `FiboChip`/`ArraySumCircuit` can be
+// monomorphized for a given op the same way they're already parameterized over `N`.
+trait Reduction: Clone {
+    const OP: ReductionOp;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SumOp;
+impl Reduction for SumOp {
+    const OP: ReductionOp = ReductionOp::Sum;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ProductOp;
+impl Reduction for ProductOp {
+    const OP: ReductionOp = ReductionOp::Product;
+}
+
+// A chip-agnostic wrapper over an assigned cell, so `NumericInstructions` (and its
+// callers) can be generic over what a chip's "numeric" value actually is instead of
+// every chip exposing raw `AssignedCell`s.
+#[derive(Clone, Debug)]
+struct Num<F: Field>(AssignedCell<F, F>);
+
+impl<F: Field> Num<F> {
+    fn cell(&self) -> &AssignedCell<F, F> {
+        &self.0
+    }
+}
+
+impl<F: Field> From<AssignedCell<F, F>> for Num<F> {
+    fn from(cell: AssignedCell<F, F>) -> Self {
+        Self(cell)
+    }
+}
+
+// Mirrors the instruction-trait pattern from halo2's own vector-mul/two-chip
+// examples: `ArraySumCircuit::synthesize` programs against this trait rather than
+// `FiboChip` directly, so an alternative accumulator (e.g. a lookup-based chip, or
+// one that folds over wider rows) can be swapped in without touching the `Circuit`
+// impl.
+trait NumericInstructions<F: Field>: Chip<F> {
+    type Num;
+
+    // Loads `xs` into fresh private cells, without yet constraining how they're used.
+    fn load_private(&self, layouter: impl Layouter<F>, xs: &[Value<F>]) -> Result<Vec<Self::Num>, Error>;
+
+    // Folds `xs` down to a single value via the chip's gate, returning the result.
+    fn accumulate(&self, layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error>;
+
+    fn expose_public(&self, layouter: impl Layouter<F>, num: &Self::Num, row: usize) -> Result<(), Error>;
+}
 
 #[derive(Debug, Clone)]
 struct ArraySumConfig {
     pub advice: [Column<Advice>; 3],
     pub selector: Selector,
+    // Enabled for every row but the first. Ties `a[row]` to `out[row-1]` via a
+    // `Rotation::prev()` gate, so the running-total chain across rows is a constraint
+    // on the witness itself rather than something only the honest `accumulate()` loop
+    // happens to produce.
+    pub chain_selector: Selector,
     pub instance: Column<Instance>,
 }
 
-struct FiboChip<F: Field, const N: usize> {
+struct FiboChip<F: Field, const N: usize, Op: Reduction = SumOp> {
     config: ArraySumConfig,
-    _marker: PhantomData<F>,
+    _marker: PhantomData<(F, Op)>,
 }
 
-impl<F: Field, const N: usize> FiboChip<F, N> {
+impl<F: Field, const N: usize, Op: Reduction> FiboChip<F, N, Op> {
     fn construct(config: ArraySumConfig) -> Self {
         Self { config, _marker: PhantomData }
     }
@@ -28,6 +115,7 @@ impl<F: Field, const N: usize> FiboChip<F, N> {
 
         // Selectors do get optimized by the backend, so no need to receive them as args
         let selector: Selector = meta.selector();
+        let chain_selector: Selector = meta.selector();
 
         // Needed to use permutation argument
         meta.enable_equality(instance);
@@ -36,56 +124,130 @@ impl<F: Field, const N: usize> FiboChip<F, N> {
             meta.enable_equality(*column);
         }
 
-        // Create a custom gate for addition. There are no pre-built gates in Halo2.
-        meta.create_gate("add", |meta| {
+        // Create a custom gate for the chip's op. There are no pre-built gates in Halo2.
+        let gate_name = match Op::OP {
+            ReductionOp::Sum => "add",
+            ReductionOp::Product => "mul",
+        };
+        meta.create_gate(gate_name, |meta| {
             let s = meta.query_selector(selector);
-            
+
             // Rotation lets us pick the current row, or a row given an offset
             let a = meta.query_advice(col_a, Rotation::cur());
             let b = meta.query_advice(col_b, Rotation::cur());
             let c  = meta.query_advice(col_accum, Rotation::cur());
-            
-            // If selector s is set, then a+b=c
-            vec![s * (a + b - c)]
+
+            // If selector s is set, then a+b=c (Sum) or a*b=c (Product)
+            let poly = match Op::OP {
+                ReductionOp::Sum => a + b - c,
+                ReductionOp::Product => a * b - c,
+            };
+            vec![s * poly]
         });
 
-        ArraySumConfig { advice: [col_a,col_b,col_accum], selector, instance }
+        // Chains the running accumulator across rows: whenever `chain_selector` is
+        // set, this row's incoming accumulator (`a`) must equal the previous row's
+        // outgoing accumulator (`out`). Row 0 has no previous row to chain from, so
+        // it's tied to a fixed identity cell instead (see `accumulate`), and
+        // `chain_selector` is left disabled there.
+        meta.create_gate("chain", |meta| {
+            let s_chain = meta.query_selector(chain_selector);
+            let a = meta.query_advice(col_a, Rotation::cur());
+            let prev_out = meta.query_advice(col_accum, Rotation::prev());
+
+            vec![s_chain * (a - prev_out)]
+        });
+
+        ArraySumConfig { advice: [col_a,col_b,col_accum], selector, chain_selector, instance }
     }
 
-    fn assign_row(&self, mut layouter: impl Layouter<F>,  xs: [Value<F>; N]) -> Result<AssignedCell<F,F>, Error>  {        
+}
+
+impl<F: Field, const N: usize, Op: Reduction> Chip<F> for FiboChip<F, N, Op> {
+    type Config = ArraySumConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: Field, const N: usize, Op: Reduction> NumericInstructions<F> for FiboChip<F, N, Op> {
+    type Num = Num<F>;
+
+    fn load_private(&self, mut layouter: impl Layouter<F>, xs: &[Value<F>]) -> Result<Vec<Self::Num>, Error> {
+        let col_x = self.config.advice[1];
         layouter.assign_region(
-            || "next row",
+            || "load private inputs",
+            |mut region| {
+                xs.iter()
+                    .enumerate()
+                    .map(|(row, &x)| {
+                        region
+                            .assign_advice(|| format!("x[{}]", row), col_x, row, || x)
+                            .map(Num::from)
+                    })
+                    .collect()
+            }
+        )
+    }
+
+    // Folds `xs` via the chip's gate, copying each value in from its already-assigned
+    // cell (enforced by the permutation argument) rather than assigning it fresh, so
+    // this also doubles as the "accumulate values another chip produced" path used by
+    // `DotProductCircuit`.
+    //
+    // `chain_selector` ties each row's `a` (the running accumulator coming in) to the
+    // previous row's `out` (the running accumulator going out) via the gate itself,
+    // with row 0's `a` copy-constrained to the op's fixed identity instead. Without
+    // that chain, the gate only proves `a[row] OP x[row] = out[row]` in isolation, so
+    // a prover could leave every row's `a` as a free witness and satisfy the last
+    // row's equation (the only one copy-constrained to the public instance) for any
+    // claimed total.
+    fn accumulate(&self, mut layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error> {
+        layouter.assign_region(
+            || "accumulate",
             |mut region| {
                 let [col_a, col_x, col_accum] = self.config.advice;
-                self.config.selector.enable(&mut region, 0)?;
                 let mut out_cell = Err(Error::Synthesis);
-                let mut accum_value: Value<F> = Value::known(F::ZERO);
-                
-                for row in 0..N {
-                    let x = xs[row];
+                let mut accum_value: Value<F> = Value::known(Op::OP.identity());
+
+                for (row, x) in xs.iter().enumerate() {
                     self.config.selector.enable(&mut region, row)?;
-                    
-                    region.assign_advice(
-                        || "a", 
-                        col_a, 
-                        row, 
+
+                    let a_cell = region.assign_advice(
+                        || "a",
+                        col_a,
+                        row,
                         || accum_value
                     )?;
+                    if row == 0 {
+                        region.constrain_constant(a_cell.cell(), Op::OP.identity::<F>())?;
+                    } else {
+                        self.config.chain_selector.enable(&mut region, row)?;
+                    }
 
-                    region.assign_advice(
+                    let x_value = x.cell().value().copied();
+                    let x_cell = region.assign_advice(
                         || format!("x[{}]", row),
                         col_x,
                         row,
-                        || x
+                        || x_value
                     )?;
+                    region.constrain_equal(x_cell.cell(), x.cell().cell())?;
 
-                    accum_value = accum_value + x;
-                    out_cell = region.assign_advice(
+                    accum_value = accum_value.zip(x_value).map(|(acc, x)| Op::OP.fold(acc, x));
+                    let out = region.assign_advice(
                         || format!("out[{}]", row),
                         col_accum,
                         row,
                         || accum_value
-                    );
+                    )?;
+                    out_cell = Ok(Num::from(out));
                 };
 
                 out_cell
@@ -93,24 +255,87 @@ impl<F: Field, const N: usize> FiboChip<F, N> {
         )
     }
 
-    pub fn expose_public(&self, mut layouter: impl Layouter<F>, cell: &AssignedCell<F, F>, row: usize) -> Result<(), Error> {
-        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+    fn expose_public(&self, mut layouter: impl Layouter<F>, num: &Self::Num, row: usize) -> Result<(), Error> {
+        layouter.constrain_instance(num.cell().cell(), self.config.instance, row)
     }
+}
 
+#[derive(Debug, Clone)]
+struct MulConfig {
+    pub advice: [Column<Advice>; 3],
+    pub selector: Selector,
 }
 
+struct MulChip<F: Field, const N: usize> {
+    config: MulConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field, const N: usize> MulChip<F, N> {
+    fn construct(config: MulConfig) -> Self {
+        Self { config, _marker: PhantomData }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>, advice: [Column<Advice>; 3]) -> MulConfig {
+        let [col_x, col_y, col_p] = advice;
+        let selector = meta.selector();
+
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+
+        // Create a custom gate for elementwise multiplication: s*(x*y-p)
+        meta.create_gate("mul", |meta| {
+            let s = meta.query_selector(selector);
+            let x = meta.query_advice(col_x, Rotation::cur());
+            let y = meta.query_advice(col_y, Rotation::cur());
+            let p = meta.query_advice(col_p, Rotation::cur());
+
+            vec![s * (x * y - p)]
+        });
+
+        MulConfig { advice: [col_x, col_y, col_p], selector }
+    }
+
+    // Assigns p[i] = xs[i]*ys[i] row by row, returning the assigned product cells so
+    // they can be copied into a downstream chip (e.g. to be summed).
+    fn assign_row(&self, mut layouter: impl Layouter<F>, xs: [Value<F>; N], ys: [Value<F>; N]) -> Result<[AssignedCell<F, F>; N], Error> {
+        layouter.assign_region(
+            || "products",
+            |mut region| {
+                let [col_x, col_y, col_p] = self.config.advice;
+                let mut products: Vec<AssignedCell<F, F>> = Vec::with_capacity(N);
+
+                for row in 0..N {
+                    self.config.selector.enable(&mut region, row)?;
+
+                    region.assign_advice(|| format!("x[{}]", row), col_x, row, || xs[row])?;
+                    region.assign_advice(|| format!("y[{}]", row), col_y, row, || ys[row])?;
+
+                    let p = xs[row].zip(ys[row]).map(|(x, y)| x * y);
+                    let cell = region.assign_advice(|| format!("p[{}]", row), col_p, row, || p)?;
+                    products.push(cell);
+                }
+
+                Ok(products.try_into().unwrap_or_else(|_| panic!("expected {} products", N)))
+            }
+        )
+    }
+}
 
-struct ArraySumCircuit<F: Field, const N: usize> {
+struct ArraySumCircuit<F: Field, const N: usize, Op: Reduction = SumOp> {
     xs: [Value<F>; N],
+    _marker: PhantomData<Op>,
 }
 
-impl<F: Field, const N: usize> Circuit<F> for ArraySumCircuit<F, N> {
+impl<F: Field, const N: usize, Op: Reduction> Circuit<F> for ArraySumCircuit<F, N, Op> {
     type Config = ArraySumConfig;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
         Self {
-            xs: [Value::unknown(); N],     
+            xs: [Value::unknown(); N],
+            _marker: PhantomData,
         }
     }
 
@@ -121,26 +346,28 @@ impl<F: Field, const N: usize> Circuit<F> for ArraySumCircuit<F, N> {
         let instance = meta.instance_column();
         let constant = meta.fixed_column();
 
-        FiboChip::<F, N>::configure(meta, [col_a, col_b, col_c], instance, constant)
+        FiboChip::<F, N, Op>::configure(meta, [col_a, col_b, col_c], instance, constant)
     }
 
     fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
-        let chip = FiboChip::construct(config);
-        
-        // Assign returns the last row from col_accum that contains the total accumulated score, 
-        // which we expose by matching it to the public instance that corresponds to round N-1
-        let out_cell = chip.assign_row(layouter.namespace(|| "rps"), self.xs)?;        
-        chip.expose_public(layouter.namespace(|| "out"), &out_cell, N-1)?;
-        
+        let chip = FiboChip::<F, N, Op>::construct(config);
+
+        // Accumulate returns the total accumulated score, which we expose by matching
+        // it to the public instance that corresponds to round N-1
+        let nums = chip.load_private(layouter.namespace(|| "rps"), &self.xs)?;
+        let out = chip.accumulate(layouter.namespace(|| "rps"), &nums)?;
+        chip.expose_public(layouter.namespace(|| "out"), &out, N-1)?;
+
         Ok(())
     }
 }
 
 // Returns the circuit configured with the private inputs, and the public inputs
-fn make_circuit<const N: usize>(xs: [u64; N], out: u64) -> (ArraySumCircuit<Fp, N>, Vec<Vec<Fp>>) {
+fn make_circuit<const N: usize, Op: Reduction>(xs: [u64; N], out: u64) -> (ArraySumCircuit<Fp, N, Op>, Vec<Vec<Fp>>) {
     // The plays in each round are the private inputs to the circuit
     let circuit = ArraySumCircuit {
         xs: xs.map(|val| Value::known(Fp::from(val))),
+        _marker: PhantomData,
     };
 
     // We can fill the public instances with zeros up until the last score,
@@ -150,8 +377,158 @@ fn make_circuit<const N: usize>(xs: [u64; N], out: u64) -> (ArraySumCircuit<Fp,
 
     (circuit, vec![outs])
 }
- 
 
+#[derive(Debug, Clone)]
+struct DotProductConfig {
+    mul: MulConfig,
+    sum: ArraySumConfig,
+}
+
+// Composes `MulChip` and `FiboChip` to prove `sum(xs[i]*ys[i])` over two private
+// arrays, exposing only the final dot product as the public instance.
+struct DotProductCircuit<F: Field, const N: usize> {
+    xs: [Value<F>; N],
+    ys: [Value<F>; N],
+}
+
+impl<F: Field, const N: usize> Circuit<F> for DotProductCircuit<F, N> {
+    type Config = DotProductConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            xs: [Value::unknown(); N],
+            ys: [Value::unknown(); N],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let col_x = meta.advice_column();
+        let col_y = meta.advice_column();
+        let col_p = meta.advice_column();
+        let mul = MulChip::<F, N>::configure(meta, [col_x, col_y, col_p]);
+
+        let col_a = meta.advice_column();
+        let col_accum = meta.advice_column();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        // col_p is shared with the mul chip above, so the products it assigns can be
+        // copied into the sum chip's input column via the permutation argument.
+        let sum = FiboChip::<F, N, SumOp>::configure(meta, [col_a, col_p, col_accum], instance, constant);
+
+        DotProductConfig { mul, sum }
+    }
+
+    fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let mul_chip = MulChip::construct(config.mul);
+        let products = mul_chip.assign_row(layouter.namespace(|| "products"), self.xs, self.ys)?;
+        let products: Vec<Num<F>> = products.into_iter().map(Num::from).collect();
+
+        let sum_chip = FiboChip::<F, N, SumOp>::construct(config.sum);
+        let out = sum_chip.accumulate(layouter.namespace(|| "dot product"), &products)?;
+        sum_chip.expose_public(layouter.namespace(|| "out"), &out, N - 1)?;
+
+        Ok(())
+    }
+}
+
+// Returns the dot-product circuit configured with the private inputs, and the public inputs
+fn make_dot_product_circuit<const N: usize>(xs: [u64; N], ys: [u64; N], out: u64) -> (DotProductCircuit<Fp, N>, Vec<Vec<Fp>>) {
+    let circuit = DotProductCircuit {
+        xs: xs.map(|val| Value::known(Fp::from(val))),
+        ys: ys.map(|val| Value::known(Fp::from(val))),
+    };
+
+    let mut outs = vec![Fp::zero(); N];
+    outs[N-1] = Fp::from(out);
+
+    (circuit, vec![outs])
+}
+
+// Runs a real IPA prover/verifier round-trip over Pasta for `circuit` against
+// `public_input`, returning whether the resulting proof verifies.
+fn prove_and_verify<const N: usize, Op: Reduction>(circuit: ArraySumCircuit<Fp, N, Op>, public_input: Vec<Vec<Fp>>) -> bool {
+    let k = 4;
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, &circuit).expect("keygen_vk should not fail");
+    let pk = keygen_pk(&params, vk, &circuit).expect("keygen_pk should not fail");
+
+    let instance_columns: Vec<&[Fp]> = public_input.iter().map(|col| col.as_slice()).collect();
+
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        &[circuit],
+        &[&instance_columns],
+        OsRng,
+        &mut transcript,
+    )
+    .expect("proof generation should not fail");
+    let proof = transcript.finalize();
+
+    let strategy = SingleVerifier::new(&params);
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(&proof[..]);
+    verify_proof(
+        &params,
+        pk.get_vk(),
+        strategy,
+        &[&instance_columns],
+        &mut transcript,
+    )
+    .is_ok()
+}
+
+// Like `prove_and_verify`, but takes the randomness source as a parameter and
+// returns the serialized proof instead of a verified/not-verified bool. This
+// crate's pinned `halo2_proofs` version has no `unblinded_advice_column` knob on
+// `ConstraintSystem`, and `create_proof` doesn't expose the blinds it samples, so
+// there's no way to mark one column's commitment as unblinded. Driving two proofs
+// with the same deterministic `rng` instead makes every advice commitment
+// reproducible bit-for-bit across both transcripts (see `advice_commitment`), which
+// is what actually lets a third party cross-check that two proofs share an advice
+// column's private values.
+fn prove_with_rng<R: RngCore, const N: usize, Op: Reduction>(
+    params: &Params<EqAffine>,
+    circuit: ArraySumCircuit<Fp, N, Op>,
+    public_input: &[Vec<Fp>],
+    rng: R,
+) -> Vec<u8> {
+    let vk = keygen_vk(params, &circuit).expect("keygen_vk should not fail");
+    let pk = keygen_pk(params, vk, &circuit).expect("keygen_pk should not fail");
+
+    let instance_columns: Vec<&[Fp]> = public_input.iter().map(|col| col.as_slice()).collect();
+
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    create_proof(
+        params,
+        &pk,
+        &[circuit],
+        &[&instance_columns],
+        rng,
+        &mut transcript,
+    )
+    .expect("proof generation should not fail");
+    transcript.finalize()
+}
+
+// Reads the `index`-th advice column's commitment directly out of a proof's
+// transcript. Instance commitments are only hashed into the transcript (the
+// verifier recomputes them from the public instance), so the advice commitments a
+// prover writes via `write_point` are the very first points in the proof bytes --
+// this reads the same bytes `verify_proof` reads before it squeezes its first
+// challenge.
+fn advice_commitment(proof: &[u8], index: usize) -> EqAffine {
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof);
+    (0..=index)
+        .map(|_| transcript.read_point().expect("proof should have this many advice commitments"))
+        .last()
+        .unwrap()
+}
+
+// Checks with `MockProver` that the sum circuit over `xs = [1, 2, 3, 10]` is
+// satisfied against the public total 16.
 #[test]
 fn test_01() {
     let k = 4;
@@ -159,9 +536,221 @@ fn test_01() {
     let xs = [1, 2, 3, 10];
     let out = 16;
 
-    let (circuit, public_input) = make_circuit(xs, out);
+    let (circuit, public_input) = make_circuit::<_, SumOp>(xs, out);
 
     let prover = MockProver::run(k, &circuit, public_input).unwrap();
     prover.assert_satisfied();
     println!("success!")
+}
+
+#[test]
+fn test_02_prove_and_verify() {
+    let xs = [1, 2, 3, 10];
+    let out = 16;
+
+    let (circuit, public_input) = make_circuit::<_, SumOp>(xs, out);
+    assert!(prove_and_verify(circuit, public_input));
+
+    // Mutating the public instance to a total the witness doesn't match should make
+    // the proof fail to verify.
+    let (instance_mismatch_circuit, mut bad_public_input) = make_circuit::<_, SumOp>(xs, out);
+    let last = xs.len() - 1;
+    bad_public_input[0][last] = Fp::from(out + 1);
+    assert!(!prove_and_verify(instance_mismatch_circuit, bad_public_input));
+
+    // Mutating a private input so the witnessed accumulator no longer sums to `out`
+    // should also make the proof fail to verify, even against the original (correct)
+    // public instance.
+    let mut bad_xs = xs;
+    bad_xs[0] += 1;
+    let (bad_witness_circuit, public_input) = make_circuit::<_, SumOp>(bad_xs, out);
+    assert!(!prove_and_verify(bad_witness_circuit, public_input));
+}
+
+#[test]
+fn test_03_product() {
+    let k = 4;
+
+    let xs = [1, 2, 3, 10];
+    let out = 60;
+
+    let (circuit, public_input) = make_circuit::<_, ProductOp>(xs, out);
+
+    let prover = MockProver::run(k, &circuit, public_input).unwrap();
+    prover.assert_satisfied();
+}
+
+#[test]
+#[allow(clippy::identity_op)]
+fn test_04_dot_product() {
+    let k = 4;
+
+    let xs = [1, 2, 3, 10];
+    let ys = [5, 4, 3, 2];
+    // Spelled out term-by-term (rather than computed) so the expected dot product is
+    // easy to check against xs/ys by eye.
+    let out = 1*5 + 2*4 + 3*3 + 10*2;
+
+    let (circuit, public_input) = make_dot_product_circuit(xs, ys, out);
+
+    let prover = MockProver::run(k, &circuit, public_input).unwrap();
+    prover.assert_satisfied();
+}
+
+// A minimal SplitMix64-based `RngCore`, used only so two proofs can be driven by
+// independently-constructed RNGs that are nonetheless guaranteed to produce the
+// same stream of output when seeded identically (unlike `OsRng`, which can't be
+// seeded at all).
+#[cfg(test)]
+struct DeterministicRng(u64);
+
+#[cfg(test)]
+impl RngCore for DeterministicRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_05_shared_column_commitment_matches_across_transcripts() {
+    let k = 4;
+    let params: Params<EqAffine> = Params::new(k);
+
+    let xs = [1, 2, 3, 10];
+
+    // One party proves a sum over `xs`, another proves a product over the same `xs`.
+    // `create_proof` draws blinds for advice commitments only after synthesizing and
+    // padding every column, in column-registration order, and never touches `rng`
+    // during synthesis itself -- so seeding both calls with the same deterministic
+    // RNG makes every column up to and including `col_x` (the shared private input,
+    // registered second) consume an identical sequence of random draws. Since `col_x`
+    // is witnessed identically in both circuits, its Lagrange polynomial and blind
+    // come out identical too, so its advice commitment is bit-for-bit identical in
+    // both proofs -- recoverable from the raw transcript bytes, not recomputed
+    // out-of-band.
+    let (sum_circuit, sum_public_input) = make_circuit::<_, SumOp>(xs, 16);
+    let sum_proof = prove_with_rng(&params, sum_circuit, &sum_public_input, DeterministicRng(1));
+
+    let (product_circuit, product_public_input) = make_circuit::<_, ProductOp>(xs, 60);
+    let product_proof = prove_with_rng(&params, product_circuit, &product_public_input, DeterministicRng(1));
+
+    assert_eq!(advice_commitment(&sum_proof, 1), advice_commitment(&product_proof, 1));
+
+    // A party proving over a different private array should not match, so the check
+    // isn't vacuously true.
+    let other_xs = [1, 2, 3, 11];
+    let (other_circuit, other_public_input) = make_circuit::<_, SumOp>(other_xs, 17);
+    let other_proof = prove_with_rng(&params, other_circuit, &other_public_input, DeterministicRng(1));
+
+    assert_ne!(advice_commitment(&sum_proof, 1), advice_commitment(&other_proof, 1));
+}
+
+// A hand-rolled circuit reusing `FiboChip::configure`'s gate directly, bypassing
+// `FiboChip::accumulate`'s Rust-level chaining loop entirely. It still drives
+// `chain_selector` for every row but the first, the same way a faithful circuit
+// would -- the only thing it skips is computing a genuine running total. Every row
+// but the last is assigned all-zero (trivially satisfying that row's local
+// `a + x = out`, and the chain gate since zero chains to zero), and the last row
+// sets `a = claimed_out - x` so only its equation ties to `claimed_out`: exercising
+// whether the gate itself (not just the honest witness-generation path) rejects an
+// accumulator that was never threaded through `xs`.
+struct DecoupledAccumulatorCircuit<F: Field, const N: usize> {
+    xs: [Value<F>; N],
+    claimed_out: Value<F>,
+}
+
+impl<F: Field, const N: usize> Circuit<F> for DecoupledAccumulatorCircuit<F, N> {
+    type Config = ArraySumConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            xs: [Value::unknown(); N],
+            claimed_out: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let col_a = meta.advice_column();
+        let col_b = meta.advice_column();
+        let col_c = meta.advice_column();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        FiboChip::<F, N, SumOp>::configure(meta, [col_a, col_b, col_c], instance, constant)
+    }
+
+    fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let out_cell = layouter.assign_region(
+            || "decoupled accumulate",
+            |mut region| {
+                let [col_a, col_x, col_accum] = config.advice;
+                let mut out_cell = Err(Error::Synthesis);
+
+                for row in 0..N {
+                    config.selector.enable(&mut region, row)?;
+                    if row > 0 {
+                        config.chain_selector.enable(&mut region, row)?;
+                    }
+
+                    let (a, x, out) = if row == N - 1 {
+                        let x = self.xs[row];
+                        let a = self.claimed_out.zip(x).map(|(out, x)| out - x);
+                        (a, x, self.claimed_out)
+                    } else {
+                        (Value::known(F::ZERO), Value::known(F::ZERO), Value::known(F::ZERO))
+                    };
+
+                    region.assign_advice(|| "a", col_a, row, || a)?;
+                    region.assign_advice(|| format!("x[{}]", row), col_x, row, || x)?;
+                    out_cell = region
+                        .assign_advice(|| format!("out[{}]", row), col_accum, row, || out)
+                        .map(Num::from);
+                }
+
+                out_cell
+            },
+        )?;
+
+        layouter.constrain_instance(out_cell.cell().cell(), config.instance, N - 1)
+    }
+}
+
+#[test]
+fn test_06_gate_rejects_decoupled_accumulator() {
+    let k = 4;
+
+    let xs = [1u64, 2, 3, 10];
+    // A claimed total with no relation to `xs`. Only the gate's row-to-row chain (not
+    // `accumulate()`'s honest witness-generation loop, which this circuit bypasses)
+    // can catch that the accumulator was never actually threaded through the array.
+    let claimed_out = 999u64;
+
+    let circuit = DecoupledAccumulatorCircuit::<Fp, 4> {
+        xs: xs.map(|val| Value::known(Fp::from(val))),
+        claimed_out: Value::known(Fp::from(claimed_out)),
+    };
+
+    let mut public_input = vec![Fp::zero(); xs.len()];
+    public_input[xs.len() - 1] = Fp::from(claimed_out);
+
+    let prover = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+    assert!(prover.verify().is_err());
 }
\ No newline at end of file
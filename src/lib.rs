@@ -0,0 +1,5 @@
+// Every chip/circuit here is exercised only from this crate's own `#[test]`s, so the
+// lib-target build (with no external caller) sees them as dead without this.
+#![allow(dead_code)]
+
+mod array_sum_example;